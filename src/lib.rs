@@ -34,6 +34,14 @@ impl<K, V> VecMap<K, V> {
         }
     }
 
+    /// Returns the number of elements the map can hold without reallocating the dense `rows`
+    /// storage.
+    #[inline]
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.rows.capacity()
+    }
+
     pub fn clear(&mut self) {
         self.keys.clear();
         self.rows.clear();
@@ -47,6 +55,14 @@ impl<K, V> VecMap<K, V> {
         self.keys.get(index(key)).map_or(false, Option::is_some)
     }
 
+    /// Clears the map, returning all key-value pairs as an iterator.
+    ///
+    /// The map is left empty even if the iterator is dropped before being fully consumed.
+    pub fn drain(&mut self) -> Drain<K, V> {
+        self.keys.clear();
+        Drain(self.rows.drain(..))
+    }
+
     #[must_use]
     pub fn entry(&mut self, key: K) -> Entry<K, V>
     where
@@ -59,6 +75,38 @@ impl<K, V> VecMap<K, V> {
         }
     }
 
+    /// Creates an iterator which uses a closure to determine if an element should be removed.
+    ///
+    /// If the closure returns `true`, the element is removed and yielded. If the closure returns
+    /// `false`, the element will remain in the map and will not be yielded.
+    ///
+    /// If the returned `ExtractIf` is not exhausted, e.g. because it is dropped without iterating
+    /// or the iteration short-circuits, then the remaining elements will be retained or removed
+    /// depending on the the predicate when the `ExtractIf` is dropped.
+    pub fn extract_if<F>(&mut self, f: F) -> ExtractIf<K, V, F>
+    where
+        F: FnMut(&K, &mut V) -> bool,
+        K: Copy + Into<usize>,
+    {
+        ExtractIf {
+            vec: self,
+            cursor: 0,
+            f,
+        }
+    }
+
+    /// Returns the smallest key present in the map, in `O(n)` over the sparse index.
+    #[must_use]
+    pub fn first_key(&self) -> Option<K>
+    where
+        K: From<usize>,
+    {
+        self.keys
+            .iter()
+            .position(Option::is_some)
+            .map(K::from)
+    }
+
     #[inline]
     #[must_use]
     pub fn get(&self, key: &K) -> Option<&V>
@@ -71,6 +119,91 @@ impl<K, V> VecMap<K, V> {
         }
     }
 
+    /// Attempts to get mutable references to `N` values at once.
+    ///
+    /// Returns `None` if any key is missing, or if two keys resolve to the same entry (since
+    /// that would hand out two mutable references to the same value).
+    ///
+    /// # Example
+    /// ```
+    /// use vec_map::VecMap;
+    ///
+    /// let mut map = VecMap::new();
+    /// map.insert(1usize, 10);
+    /// map.insert(2usize, 20);
+    ///
+    /// let [a, b] = map.get_disjoint_mut([1, 2]).unwrap();
+    /// *a += 1;
+    /// *b += 1;
+    ///
+    /// assert_eq!(map.get(&1), Some(&11));
+    /// assert_eq!(map.get(&2), Some(&21));
+    ///
+    /// assert!(map.get_disjoint_mut([1, 1]).is_none());
+    /// ```
+    pub fn get_disjoint_mut<const N: usize>(&mut self, keys: [K; N]) -> Option<[&mut V; N]>
+    where
+        K: Copy + Into<usize>,
+    {
+        let mut row_indices = [0usize; N];
+
+        for (slot, key) in row_indices.iter_mut().zip(keys.iter()) {
+            *slot = match self.keys.get(index(key)) {
+                Some(Some(row_index)) => *row_index as usize,
+                _ => return None,
+            };
+        }
+
+        for i in 0..N {
+            for j in (i + 1)..N {
+                if row_indices[i] == row_indices[j] {
+                    return None;
+                }
+            }
+        }
+
+        let ptr = self.rows.as_mut_ptr();
+        let refs = row_indices
+            .iter()
+            .map(|&row_index| unsafe { &mut (*ptr.add(row_index)).1 })
+            .collect::<Vec<_>>();
+
+        refs.try_into().ok()
+    }
+
+    /// Slice-based variant of [`Self::get_disjoint_mut`] for a number of keys not known at
+    /// compile time.
+    pub fn get_disjoint_mut_slice(&mut self, keys: &[K]) -> Option<Vec<&mut V>>
+    where
+        K: Copy + Into<usize>,
+    {
+        let mut row_indices = Vec::with_capacity(keys.len());
+
+        for key in keys {
+            match self.keys.get(index(key)) {
+                Some(Some(row_index)) => row_indices.push(*row_index as usize),
+                _ => return None,
+            }
+        }
+
+        for i in 0..row_indices.len() {
+            for j in (i + 1)..row_indices.len() {
+                if row_indices[i] == row_indices[j] {
+                    return None;
+                }
+            }
+        }
+
+        let ptr = self.rows.as_mut_ptr();
+
+        Some(
+            row_indices
+                .into_iter()
+                .map(|row_index| unsafe { &mut (*ptr.add(row_index)).1 })
+                .collect(),
+        )
+    }
+
     #[inline]
     #[must_use]
     pub fn get_mut(&mut self, key: &K) -> Option<&mut V>
@@ -127,16 +260,72 @@ impl<K, V> VecMap<K, V> {
         IterMut(self.rows.iter_mut())
     }
 
+    /// Iterates over entries in ascending key order, using the sparse index rather than the
+    /// insertion-ordered `rows`.
+    pub fn iter_ordered(&self) -> impl Iterator<Item = (K, &V)>
+    where
+        K: From<usize>,
+    {
+        self.keys.iter().enumerate().filter_map(move |(i, slot)| {
+            slot.map(|row_index| (K::from(i), &self.rows[row_index as usize].1))
+        })
+    }
+
     #[inline]
     pub fn keys(&self) -> Keys<K, V> {
         Keys(self.iter())
     }
 
+    /// Returns the largest key present in the map, in `O(n)` over the sparse index.
+    #[must_use]
+    pub fn last_key(&self) -> Option<K>
+    where
+        K: From<usize>,
+    {
+        self.keys
+            .iter()
+            .rposition(Option::is_some)
+            .map(K::from)
+    }
+
     #[inline]
     pub fn len(&self) -> usize {
         self.rows.len()
     }
 
+    /// Iterates, in ascending key order, over the entries whose key falls within `range`.
+    ///
+    /// Walks the sparse index like [`Self::iter_ordered`], but only the slice covered by
+    /// `range`, so the cost is proportional to the width of `range`, not the number of
+    /// occupied entries.
+    pub fn range<R>(&self, range: R) -> impl Iterator<Item = (K, &V)>
+    where
+        K: From<usize>,
+        R: std::ops::RangeBounds<usize>,
+    {
+        let start = match range.start_bound() {
+            std::ops::Bound::Included(&s) => s,
+            std::ops::Bound::Excluded(&s) => s + 1,
+            std::ops::Bound::Unbounded => 0,
+        };
+
+        let end = match range.end_bound() {
+            std::ops::Bound::Included(&e) => e + 1,
+            std::ops::Bound::Excluded(&e) => e,
+            std::ops::Bound::Unbounded => self.keys.len(),
+        };
+
+        let end = end.min(self.keys.len());
+        let start = start.min(end);
+
+        self.keys[start..end]
+            .iter()
+            .enumerate()
+            .filter_map(move |(i, slot)| {
+                slot.map(|row_index| (K::from(start + i), &self.rows[row_index as usize].1))
+            })
+    }
+
     pub fn remove(&mut self, key: &K) -> Option<V>
     where
         K: Copy + Into<usize>,
@@ -159,6 +348,28 @@ impl<K, V> VecMap<K, V> {
         }
     }
 
+    /// Reserves capacity for at least `additional` more elements in the dense `rows` storage.
+    pub fn reserve(&mut self, additional: usize) {
+        self.rows.reserve(additional);
+    }
+
+    /// Reserves the minimum capacity for at least `additional` more elements in the dense
+    /// `rows` storage, without over-allocating.
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.rows.reserve_exact(additional);
+    }
+
+    /// Ensures the sparse `keys` index has room for `max_key` without repeated reallocation as
+    /// `keys` grows to cover it.
+    ///
+    /// Unlike [`Self::reserve`], which sizes the dense `rows` storage by element count, this
+    /// sizes the sparse index by the largest key a caller is about to insert.
+    pub fn reserve_keys(&mut self, max_key: usize) {
+        if let Some(additional) = (max_key + 1).checked_sub(self.keys.len()) {
+            self.keys.reserve(additional);
+        }
+    }
+
     /// Retains only the elements specified by the predicate.
     ///
     /// In other words, remove all elements e such that f(&e) returns false. This method operates in place,
@@ -201,6 +412,11 @@ impl<K, V> VecMap<K, V> {
         })
     }
 
+    /// Shrinks the dense `rows` storage to a lower bound, keeping at least `min_capacity`.
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.rows.shrink_to(min_capacity);
+    }
+
     pub fn shrink_to_fit(&mut self) {
         if let Some(index) = self
             .keys
@@ -309,6 +525,28 @@ where
     }
 }
 
+impl<K, V> std::ops::Index<K> for VecMap<K, V>
+where
+    K: Copy + Into<usize>,
+{
+    type Output = V;
+
+    #[inline]
+    fn index(&self, key: K) -> &V {
+        self.get(&key).expect("no entry found for key")
+    }
+}
+
+impl<K, V> std::ops::IndexMut<K> for VecMap<K, V>
+where
+    K: Copy + Into<usize>,
+{
+    #[inline]
+    fn index_mut(&mut self, key: K) -> &mut V {
+        self.get_mut(&key).expect("no entry found for key")
+    }
+}
+
 impl<K, V> Eq for VecMap<K, V>
 where
     K: Eq + PartialEq,
@@ -326,6 +564,29 @@ where
     }
 }
 
+pub struct Drain<'a, K, V>(std::vec::Drain<'a, (K, V)>);
+
+impl<'a, K, V> DoubleEndedIterator for Drain<'a, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back()
+    }
+}
+
+impl<'a, K, V> Iterator for Drain<'a, K, V> {
+    type Item = (K, V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
 pub enum Entry<'a, K: 'a, V: 'a> {
     Occupied(OccupiedEntry<'a, K, V>),
     Vacant(VacantEntry<'a, K, V>),
@@ -401,6 +662,63 @@ impl<'a, K, V> Entry<'a, K, V> {
     }
 }
 
+pub struct ExtractIf<'a, K, V, F>
+where
+    K: Copy + Into<usize>,
+    F: FnMut(&K, &mut V) -> bool,
+{
+    vec: &'a mut VecMap<K, V>,
+    cursor: usize,
+    f: F,
+}
+
+impl<'a, K, V, F> Drop for ExtractIf<'a, K, V, F>
+where
+    K: Copy + Into<usize>,
+    F: FnMut(&K, &mut V) -> bool,
+{
+    fn drop(&mut self) {
+        while self.next().is_some() {}
+    }
+}
+
+impl<'a, K, V, F> Iterator for ExtractIf<'a, K, V, F>
+where
+    K: Copy + Into<usize>,
+    F: FnMut(&K, &mut V) -> bool,
+{
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.cursor < self.vec.rows.len() {
+            let row_index = self.cursor;
+            let remove = {
+                let (k, v) = &mut self.vec.rows[row_index];
+                (self.f)(k, v)
+            };
+
+            if !remove {
+                self.cursor += 1;
+                continue;
+            }
+
+            let removed_key = self.vec.rows[row_index].0;
+
+            if self.vec.rows.len() - 1 != row_index {
+                if let Some(k) = self.vec.rows.last().map(|t| index(&t.0)) {
+                    *self.vec.keys.get_mut(k).expect("key") = Some(row_index as u32);
+                }
+            }
+
+            *self.vec.keys.get_mut(index(&removed_key)).expect("key") = None;
+
+            return Some(self.vec.rows.swap_remove(row_index));
+        }
+
+        None
+    }
+}
+
 pub struct IntoIter<K, V>(std::vec::IntoIter<(K, V)>);
 
 impl<K, V> DoubleEndedIterator for IntoIter<K, V> {
@@ -735,3 +1053,241 @@ fn test_remove() {
 
     assert_eq!(vec.len(), 0);
 }
+
+#[test]
+fn test_index() {
+    let mut vec = VecMap::new();
+
+    vec.insert(1usize, "one");
+    vec.insert(2usize, "two");
+
+    assert_eq!(vec[1], "one");
+    assert_eq!(vec[2], "two");
+
+    vec[2] = "deux";
+
+    assert_eq!(vec.get(&2), Some(&"deux"));
+    assert_eq!(&mut vec[2], &mut "deux");
+}
+
+#[test]
+#[should_panic(expected = "no entry found for key")]
+fn test_index_missing_key_panics() {
+    let vec: VecMap<usize, &str> = VecMap::new();
+    let _ = vec[0];
+}
+
+#[test]
+#[should_panic(expected = "no entry found for key")]
+fn test_index_mut_missing_key_panics() {
+    let mut vec: VecMap<usize, &str> = VecMap::new();
+    vec[0] = "nope";
+}
+
+#[test]
+fn test_drain() {
+    let mut vec = VecMap::new();
+
+    for n in 0..30usize {
+        vec.insert(n, n);
+    }
+
+    let mut drained = vec.drain().collect::<Vec<_>>();
+    drained.sort_by_key(|t| t.0);
+
+    assert_eq!(drained, (0..30usize).map(|n| (n, n)).collect::<Vec<_>>());
+    assert!(vec.is_empty());
+    assert_eq!(vec.get(&0), None);
+}
+
+#[test]
+fn test_extract_if() {
+    let mut vec = VecMap::new();
+
+    for n in 0..30usize {
+        vec.insert(n, n);
+    }
+
+    let mut extracted = vec.extract_if(|_k, v| *v % 2 == 0).collect::<Vec<_>>();
+    extracted.sort_by_key(|t| t.0);
+
+    assert_eq!(
+        extracted,
+        (0..30usize).filter(|n| n % 2 == 0).map(|n| (n, n)).collect::<Vec<_>>()
+    );
+    assert_eq!(vec.len(), 15);
+
+    for n in (0..30usize).filter(|n| n % 2 == 0) {
+        assert_eq!(vec.get(&n), None);
+    }
+
+    for n in (0..30usize).filter(|n| n % 2 != 0) {
+        assert_eq!(vec.get(&n), Some(&n));
+    }
+}
+
+#[test]
+fn test_extract_if_drop_without_iterating() {
+    let mut vec = VecMap::new();
+
+    for n in 0..10usize {
+        vec.insert(n, n);
+    }
+
+    vec.extract_if(|_k, v| *v % 2 == 0);
+
+    assert_eq!(vec.len(), 5);
+
+    for n in (0..10usize).filter(|n| n % 2 != 0) {
+        assert_eq!(vec.get(&n), Some(&n));
+    }
+}
+
+#[test]
+fn test_iter_ordered() {
+    let mut vec = VecMap::new();
+
+    for n in (0..30usize).rev() {
+        vec.insert(n, n);
+    }
+
+    let ordered = vec.iter_ordered().map(|(k, v)| (k, *v)).collect::<Vec<_>>();
+
+    assert_eq!(ordered, (0..30usize).map(|n| (n, n)).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_range() {
+    let mut vec = VecMap::new();
+
+    for n in 0..30usize {
+        vec.insert(n, n);
+    }
+
+    let ranged = vec.range(10..20).map(|(k, v)| (k, *v)).collect::<Vec<_>>();
+
+    assert_eq!(ranged, (10..20usize).map(|n| (n, n)).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_range_skips_holes() {
+    let mut vec = VecMap::new();
+
+    vec.insert(1usize, 1);
+    vec.insert(5usize, 5);
+
+    let ranged = vec.range(0..10).map(|(k, v)| (k, *v)).collect::<Vec<_>>();
+
+    assert_eq!(ranged, vec![(1, 1), (5, 5)]);
+}
+
+#[test]
+fn test_first_key_last_key() {
+    let mut vec: VecMap<usize, usize> = VecMap::new();
+
+    assert_eq!(vec.first_key(), None);
+    assert_eq!(vec.last_key(), None);
+
+    vec.insert(5usize, 5);
+    vec.insert(1usize, 1);
+    vec.insert(9usize, 9);
+
+    assert_eq!(vec.first_key(), Some(1));
+    assert_eq!(vec.last_key(), Some(9));
+}
+
+#[test]
+fn test_get_disjoint_mut() {
+    let mut vec = VecMap::new();
+
+    vec.insert(1usize, 10);
+    vec.insert(2usize, 20);
+    vec.insert(3usize, 30);
+
+    let [a, b] = vec.get_disjoint_mut([3, 1]).unwrap();
+    *a += 1;
+    *b += 1;
+
+    assert_eq!(vec.get(&1), Some(&11));
+    assert_eq!(vec.get(&3), Some(&31));
+}
+
+#[test]
+fn test_get_disjoint_mut_missing_key() {
+    let mut vec = VecMap::new();
+
+    vec.insert(1usize, 10);
+
+    assert!(vec.get_disjoint_mut([1, 2]).is_none());
+}
+
+#[test]
+fn test_get_disjoint_mut_duplicate_key() {
+    let mut vec = VecMap::new();
+
+    vec.insert(1usize, 10);
+    vec.insert(2usize, 20);
+
+    assert!(vec.get_disjoint_mut([1, 1]).is_none());
+}
+
+#[test]
+fn test_get_disjoint_mut_slice() {
+    let mut vec = VecMap::new();
+
+    vec.insert(1usize, 10);
+    vec.insert(2usize, 20);
+    vec.insert(3usize, 30);
+
+    let mut refs = vec.get_disjoint_mut_slice(&[2, 3]).unwrap();
+    *refs[0] += 1;
+    *refs[1] += 1;
+
+    assert_eq!(vec.get(&2), Some(&21));
+    assert_eq!(vec.get(&3), Some(&31));
+
+    assert!(vec.get_disjoint_mut_slice(&[2, 2]).is_none());
+    assert!(vec.get_disjoint_mut_slice(&[1, 9]).is_none());
+}
+
+#[test]
+fn test_capacity_and_reserve() {
+    let mut vec: VecMap<usize, usize> = VecMap::new();
+
+    assert_eq!(vec.capacity(), 0);
+
+    vec.reserve(10);
+    assert!(vec.capacity() >= 10);
+
+    vec.reserve_exact(20);
+    assert!(vec.capacity() >= 20);
+}
+
+#[test]
+fn test_reserve_keys() {
+    let mut vec: VecMap<usize, usize> = VecMap::new();
+
+    vec.reserve_keys(99);
+    assert!(vec.keys.capacity() >= 100);
+
+    vec.insert(99usize, 99);
+    assert_eq!(vec.get(&99), Some(&99));
+}
+
+#[test]
+fn test_shrink_to() {
+    let mut vec = VecMap::new();
+
+    vec.reserve(100);
+
+    for n in 0..10usize {
+        vec.insert(n, n);
+    }
+
+    vec.shrink_to(10);
+    assert!(vec.capacity() < 100);
+
+    for n in 0..10usize {
+        assert_eq!(vec.get(&n), Some(&n));
+    }
+}
@@ -1,7 +1,8 @@
 use crate::VecMap;
 use rayon::{
     iter::{
-        IntoParallelIterator, IntoParallelRefIterator, IntoParallelRefMutIterator, ParallelIterator,
+        FromParallelIterator, IntoParallelIterator, IntoParallelRefIterator,
+        IntoParallelRefMutIterator, ParallelExtend, ParallelIterator,
     },
     slice::{Iter, IterMut},
 };
@@ -22,6 +23,54 @@ impl<K, V> VecMap<K, V> {
     {
         ParIterMut(self.rows.par_iter_mut())
     }
+
+    pub fn par_values_mut(&mut self) -> ParValuesMut<K, V>
+    where
+        K: Send,
+        V: Send,
+    {
+        ParValuesMut(self.rows.par_iter_mut())
+    }
+}
+
+impl<K, V> FromParallelIterator<(K, V)> for VecMap<K, V>
+where
+    K: Copy + Into<usize> + Send,
+    V: Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = (K, V)>,
+    {
+        let mut map = Self::new();
+        map.par_extend(par_iter);
+        map
+    }
+}
+
+impl<K, V> ParallelExtend<(K, V)> for VecMap<K, V>
+where
+    K: Copy + Into<usize> + Send,
+    V: Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = (K, V)>,
+    {
+        let items: Vec<(K, V)> = par_iter.into_par_iter().collect();
+
+        if let Some(max_key) = items.iter().map(|(k, _)| crate::index(k)).max() {
+            if self.keys.len() <= max_key {
+                self.keys.resize(max_key + 1, None);
+            }
+        }
+
+        self.rows.reserve(items.len());
+
+        for (k, v) in items {
+            self.insert(k, v);
+        }
+    }
 }
 
 impl<'a, K: Copy + Send + Sync, V: Sync> IntoParallelIterator for &'a VecMap<K, V> {
@@ -72,6 +121,19 @@ where
     }
 }
 
+pub struct ParValuesMut<'a, K: Send, V: Send>(IterMut<'a, (K, V)>);
+
+impl<'a, K: Send, V: Send> ParallelIterator for ParValuesMut<'a, K, V> {
+    type Item = &'a mut V;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: rayon::iter::plumbing::UnindexedConsumer<Self::Item>,
+    {
+        self.0.map(|t| &mut t.1).drive_unindexed(consumer)
+    }
+}
+
 #[test]
 fn test_rayon() {
     use std::ops::Rem;
@@ -99,3 +161,47 @@ fn test_rayon_mut() {
 
     (&mut vm).into_par_iter().for_each(|(_, v)| *v = *v + 1);
 }
+
+#[test]
+fn test_from_par_iter() {
+    let vm = (0..1000)
+        .into_par_iter()
+        .map(|i| (i, i))
+        .collect::<VecMap<usize, usize>>();
+
+    assert_eq!(vm.len(), 1000);
+
+    for i in 0..1000usize {
+        assert_eq!(vm.get(&i), Some(&i));
+    }
+}
+
+#[test]
+fn test_par_extend() {
+    let mut vm = (0..500)
+        .into_iter()
+        .map(|i| (i, i))
+        .collect::<VecMap<usize, usize>>();
+
+    vm.par_extend((500..1000).into_par_iter().map(|i| (i, i)));
+
+    assert_eq!(vm.len(), 1000);
+
+    for i in 0..1000usize {
+        assert_eq!(vm.get(&i), Some(&i));
+    }
+}
+
+#[test]
+fn test_par_values_mut() {
+    let mut vm = (0..1000)
+        .into_iter()
+        .map(|i| (i, i))
+        .collect::<VecMap<usize, usize>>();
+
+    vm.par_values_mut().for_each(|v| *v *= 2);
+
+    for i in 0..1000usize {
+        assert_eq!(vm.get(&i), Some(&(i * 2)));
+    }
+}